@@ -15,7 +15,7 @@ fn levenshtein(a: &str, b: &str, limit: Option<u32>) -> u32 {
     }
 }
 
-/// Formats the sum of two numbers as string.
+// Formats the sum of two numbers as string.
 // #[pyfunction]
 // #[inline]
 // fn levenshtein_advanced(
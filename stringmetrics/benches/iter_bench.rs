@@ -1,3 +1,11 @@
+// These benchmarks intentionally measure iteration rather than using `len()`
+#![allow(
+    clippy::bytes_count_to_len,
+    clippy::iter_count,
+    clippy::map_clone,
+    clippy::suspicious_map
+)]
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 const SOMESTR: &str = "This is a new string with some amount of length";
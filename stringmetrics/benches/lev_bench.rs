@@ -1,5 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use stringmetrics::{levenshtein, levenshtein_limit, levenshtein_weight, LevWeights};
+use stringmetrics::{
+    levenshtein, levenshtein_limit, levenshtein_limit_iter, levenshtein_weight, BatchLevenshtein,
+    LevWeights,
+};
 
 const BENCH_WEIGHTS: bool = false;
 const BENCH_LENDIFF: bool = true;
@@ -94,5 +97,59 @@ pub fn bench_lev_long(c: &mut Criterion) {
     }
 }
 
-criterion_group!(bench, bench_lev, bench_lev_empty, bench_lev_long);
+/// Compare against the scalar implementation, which `levenshtein` replaces
+/// for short strings with the `simd` feature. Run with `--features simd`.
+pub fn bench_lev_simd(c: &mut Criterion) {
+    c.bench_function("Levenshtein Scalar", |b| {
+        b.iter(|| {
+            levenshtein_limit_iter(black_box(STR_A).bytes(), black_box(STR_B).bytes(), u32::MAX)
+        })
+    });
+    c.bench_function("Levenshtein Scalar Different Lengths", |b| {
+        b.iter(|| {
+            levenshtein_limit_iter(
+                black_box(STR_A_LENDIFF).bytes(),
+                black_box(STR_B_LENDIFF).bytes(),
+                u32::MAX,
+            )
+        })
+    });
+}
+
+/// Compare one query against a sorted word list, with and without reusing
+/// shared prefixes
+pub fn bench_lev_batch(c: &mut Criterion) {
+    let mut words: Vec<&str> = STR_A_LONG
+        .split(|ch: char| !ch.is_alphabetic())
+        .chain(STR_B_LONG.split(|ch: char| !ch.is_alphabetic()))
+        .filter(|w| !w.is_empty())
+        .collect();
+    words.sort_unstable();
+    words.dedup();
+
+    c.bench_function("Levenshtein Word List", |b| {
+        b.iter(|| {
+            for word in &words {
+                black_box(levenshtein(black_box("consectetur"), word));
+            }
+        })
+    });
+    c.bench_function("Levenshtein Word List Batch", |b| {
+        b.iter(|| {
+            let mut batch = BatchLevenshtein::new(black_box("consectetur"));
+            for word in &words {
+                black_box(batch.distance(word));
+            }
+        })
+    });
+}
+
+criterion_group!(
+    bench,
+    bench_lev,
+    bench_lev_empty,
+    bench_lev_long,
+    bench_lev_simd,
+    bench_lev_batch
+);
 criterion_main!(bench);
@@ -17,16 +17,31 @@
 //! assert_eq!(levenshtein(a, b), 6);
 //! ```
 
+mod anagram_impl;
 mod hamming_impl;
 // mod damerau;
 mod jaccard_impl;
+mod keyboard_impl;
 mod lev_impl;
+mod metric_impl;
+mod ngram_impl;
+mod substring_impl;
 
-pub use self::hamming_impl::{hamming, hamming_iter};
+pub use self::anagram_impl::{
+    anagram_distance, anagram_distance_opts, is_anagram, is_anagram_opts, AnagramOpts,
+};
+pub use self::hamming_impl::{hamming, hamming_iter, hamming_padded, hamming_padded_iter};
 // pub use self::damerau::damerau_levenshtein;
 pub use self::jaccard_impl::{jaccard, jaccard_set};
+pub use self::keyboard_impl::{levenshtein_keyboard, KeyLayout};
 pub use self::lev_impl::{
-    levenshtein, levenshtein_limit, levenshtein_limit_iter, levenshtein_weight,
+    levenshtein, levenshtein_by, levenshtein_ignoring, levenshtein_limit, levenshtein_limit_iter,
+    levenshtein_limit_truncated, levenshtein_similarity, levenshtein_weight,
     levenshtein_weight_iter, try_levenshtein, try_levenshtein_iter, try_levenshtein_weight,
-    try_levenshtein_weight_iter, LevWeights,
+    try_levenshtein_weight_iter, BatchLevenshtein, CountUnit, LevWeights,
+};
+pub use self::metric_impl::{similarity_sort, Metric};
+pub use self::ngram_impl::{
+    overlap_coefficient, sorensen_dice, sorensen_dice_n, tversky, NgramProfile,
 };
+pub use self::substring_impl::longest_common_substring;
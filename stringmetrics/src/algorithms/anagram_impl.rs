@@ -0,0 +1,178 @@
+//! # Anagram tools (module not reexported)
+
+use std::collections::HashMap;
+
+/// Options that control how characters are compared by the anagram functions
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AnagramOpts {
+    /// Compare characters by their lowercase form
+    pub ignore_case: bool,
+    /// Skip whitespace characters entirely
+    pub ignore_whitespace: bool,
+}
+
+impl AnagramOpts {
+    #[inline]
+    pub const fn new(ignore_case: bool, ignore_whitespace: bool) -> Self {
+        Self {
+            ignore_case,
+            ignore_whitespace,
+        }
+    }
+}
+
+impl Default for AnagramOpts {
+    /// Compare all characters exactly
+    #[inline]
+    fn default() -> Self {
+        Self::new(false, false)
+    }
+}
+
+/// Count the difference in character occurrences between `a` and `b`
+///
+/// Positive counts are characters with more occurrences in `a`, negative with
+/// more in `b`.
+fn char_count_diff(a: &str, b: &str, opts: &AnagramOpts) -> HashMap<char, i64> {
+    let mut counts = HashMap::new();
+    let mut add_chars = |s: &str, delta: i64| {
+        for c in s.chars() {
+            if opts.ignore_whitespace && c.is_whitespace() {
+                continue;
+            }
+            if opts.ignore_case {
+                for lower in c.to_lowercase() {
+                    *counts.entry(lower).or_insert(0) += delta;
+                }
+            } else {
+                *counts.entry(c).or_insert(0) += delta;
+            }
+        }
+    };
+
+    add_chars(a, 1);
+    add_chars(b, -1);
+    counts
+}
+
+/// Check whether two strings are anagrams of each other
+///
+/// Two strings are anagrams if they contain exactly the same characters the
+/// same number of times, in any order. All characters are significant; use
+/// [`is_anagram_opts`] to ignore case or whitespace.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::is_anagram;
+///
+/// assert!(is_anagram("listen", "silent"));
+/// assert!(!is_anagram("listen", "Silent"));
+/// ```
+#[inline]
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    is_anagram_opts(a, b, &AnagramOpts::default())
+}
+
+/// Check whether two strings are anagrams, with configurable comparison
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::{is_anagram_opts, AnagramOpts};
+///
+/// let opts = AnagramOpts::new(true, true);
+/// assert!(is_anagram_opts("Dormitory", "dirty room", &opts));
+/// ```
+#[inline]
+pub fn is_anagram_opts(a: &str, b: &str, opts: &AnagramOpts) -> bool {
+    char_count_diff(a, b, opts).values().all(|&v| v == 0)
+}
+
+/// Count the character differences between two strings, ignoring order
+///
+/// This is the number of characters that would need to be added to or removed
+/// from either string to make the two anagrams of each other, so anagrams
+/// have a distance of 0. All characters are significant; use
+/// [`anagram_distance_opts`] to ignore case or whitespace.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::anagram_distance;
+///
+/// assert_eq!(anagram_distance("listen", "silent"), 0);
+/// assert_eq!(anagram_distance("listen", "silence"), 3);
+/// ```
+#[inline]
+pub fn anagram_distance(a: &str, b: &str) -> u32 {
+    anagram_distance_opts(a, b, &AnagramOpts::default())
+}
+
+/// Count the character differences between two strings with configurable
+/// comparison
+///
+/// See [`anagram_distance`] for details.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::{anagram_distance_opts, AnagramOpts};
+///
+/// let opts = AnagramOpts::new(true, false);
+/// assert_eq!(anagram_distance_opts("Listen", "silent", &opts), 0);
+/// ```
+#[inline]
+pub fn anagram_distance_opts(a: &str, b: &str, opts: &AnagramOpts) -> u32 {
+    let total: u64 = char_count_diff(a, b, opts)
+        .values()
+        .map(|v| v.unsigned_abs())
+        .sum();
+    total.try_into().expect("> u32::MAX items")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_anagram() {
+        assert!(is_anagram("listen", "silent"));
+        assert!(is_anagram("", ""));
+        assert!(!is_anagram("listen", "silents"));
+        assert!(!is_anagram("aab", "abb"));
+    }
+
+    #[test]
+    fn test_is_anagram_opts() {
+        assert!(!is_anagram("Listen", "silent"));
+        assert!(!is_anagram("dormitory", "dirty room"));
+        assert!(is_anagram_opts(
+            "Listen",
+            "silent",
+            &AnagramOpts::new(true, false)
+        ));
+        assert!(is_anagram_opts(
+            "dormitory",
+            "dirty room",
+            &AnagramOpts::new(false, true)
+        ));
+    }
+
+    #[test]
+    fn test_anagram_distance() {
+        assert_eq!(anagram_distance("listen", "silent"), 0);
+        // One extra character
+        assert_eq!(anagram_distance("listen", "silents"), 1);
+        // One character swapped for another
+        assert_eq!(anagram_distance("listen", "silenz"), 2);
+        assert_eq!(anagram_distance("abc", ""), 3);
+    }
+
+    #[test]
+    fn test_anagram_distance_opts() {
+        let opts = AnagramOpts::new(true, true);
+        assert_eq!(anagram_distance("Dormitory", "dirty room"), 3);
+        assert_eq!(anagram_distance_opts("Dormitory", "dirty room", &opts), 0);
+    }
+}
@@ -79,6 +79,58 @@ pub fn hamming_iter<I: IntoIterator<Item = T>, T: PartialEq>(
     Ok(distance)
 }
 
+/// Hamming distance computation that allows strings of unequal length
+///
+/// The shorter string is treated as if it were padded at the end, with every
+/// padded position counted as a substitution. This means the result is the
+/// regular hamming distance over the common length, plus the difference in
+/// lengths. For strict equal-length behavior, use [`hamming`].
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::hamming_padded;
+///
+/// assert_eq!(hamming_padded("abc", "abcd"), 1);
+/// assert_eq!(hamming_padded("abcdefg", "aaadefa"), 3);
+/// ```
+#[inline]
+pub fn hamming_padded(a: &str, b: &str) -> u32 {
+    hamming_padded_iter(a.chars(), b.chars())
+}
+
+/// Apply the padded hamming function to iterators
+///
+/// See [`hamming_padded`] for details.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::hamming_padded_iter;
+///
+/// assert_eq!(hamming_padded_iter(1..3, 2..6), 4);
+/// ```
+#[inline]
+pub fn hamming_padded_iter<I: IntoIterator<Item = T>, T: PartialEq>(a: I, b: I) -> u32 {
+    let mut distance = 0u32;
+    let mut a_iter = a.into_iter();
+    let mut b_iter = b.into_iter();
+
+    loop {
+        match (a_iter.next(), b_iter.next()) {
+            (Some(aa), Some(bb)) => {
+                if aa != bb {
+                    distance += 1;
+                }
+            }
+            (Some(_), None) | (None, Some(_)) => distance += 1,
+            (None, None) => break,
+        }
+    }
+
+    distance
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,6 +153,16 @@ mod tests {
         assert_eq!(hamming("abcdefg", "0bc1ef2"), Ok(3));
     }
 
+    #[test]
+    fn test_padded() {
+        assert_eq!(hamming_padded("abc", "abcd"), 1);
+        assert_eq!(hamming_padded("abcd", "abc"), 1);
+        assert_eq!(hamming_padded("abc", "xbcde"), 3);
+        assert_eq!(hamming_padded("", "abc"), 3);
+        assert_eq!(hamming_padded("", ""), 0);
+        assert_eq!(hamming_padded("abcdefg", "0bc1ef2"), 3);
+    }
+
     #[test]
     fn test_iter() {
         assert_eq!(hamming_iter(1..3, 2..4), Ok(2));
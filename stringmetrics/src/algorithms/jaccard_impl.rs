@@ -5,7 +5,8 @@ use std::hash::{BuildHasher, Hash};
 
 /// Calculate the Jaccard index on two [`HashSet`]s.
 ///
-/// Returns the mathematical Jaccard index, i.e. `|A ∩ B| / |A ∪ B|`
+/// Returns the mathematical Jaccard index, i.e. `|A ∩ B| / |A ∪ B|`. Two
+/// empty sets are considered identical, with an index of 1.0.
 ///
 /// Usually this is interfaced via [`jaccard`]; that is recommended unless your
 /// data is already in a `HashSet`.
@@ -31,18 +32,23 @@ pub fn jaccard_set<T, S: BuildHasher>(a: &HashSet<T, S>, b: &HashSet<T, S>) -> f
 where
     T: Eq + Hash,
 {
-    let ii: f32 = a.intersection(b).count() as f32;
-    let uu: f32 = a.union(b).count() as f32;
-    ii / uu
+    let uu = a.union(b).count();
+    // Avoid dividing by zero; two empty sets are equal
+    if uu == 0 {
+        return 1.0;
+    }
+    let ii = a.intersection(b).count();
+    ii as f32 / uu as f32
 }
 
 /// Calculate the Jaccard index on two iterators using [`jaccard_set`]
 ///
-/// Returns the mathematical Jaccard index, i.e. `|A ∩ B| / |A ∪ B|`. Iterators
-/// can point to anything hashable. Often this is combined with an iterator
-/// adapter such as [`std::str::Split`] and/or [`core::slice::Windows`] to
-/// generate n-grams for text similarity. See [this wikipedia
-/// page](https://en.wikipedia.org/wiki/N-gram) for descriptions on n-grams.
+/// Returns the mathematical Jaccard index, i.e. `|A ∩ B| / |A ∪ B|`, or 1.0 if
+/// both iterators are empty. Iterators can point to anything hashable. Often
+/// this is combined with an iterator adapter such as [`std::str::Split`]
+/// and/or [`core::slice::Windows`] to generate n-grams for text similarity. See
+/// [this wikipedia page](https://en.wikipedia.org/wiki/N-gram) for
+/// descriptions on n-grams.
 ///
 /// Note: If the data are interested in is already in a `HashSet`, use
 /// [`jaccard_set`] to save the collection step.
@@ -86,12 +92,22 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_jaccard_empty() {
-        assert!(jaccard("".chars(), "".chars()).is_nan());
+        assert_eq!(jaccard("".chars(), "".chars()), 1f32);
+    }
+
+    #[test]
+    fn test_jaccard_set_empty() {
+        let empty: HashSet<char> = HashSet::new();
+        let full = HashSet::from(['a', 'b']);
+        assert_eq!(jaccard_set(&empty, &empty), 1f32);
+        assert_eq!(jaccard_set(&empty, &full), 0f32);
+        assert_eq!(jaccard_set(&full, &empty), 0f32);
     }
 
     #[test]
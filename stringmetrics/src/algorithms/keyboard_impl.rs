@@ -0,0 +1,194 @@
+//! # Keyboard-aware distance tools (module not reexported)
+
+use std::collections::{HashMap, HashSet};
+
+/// Default cost of substituting a key for one of its neighbors
+const DEFAULT_ADJACENT_COST: f64 = 0.5;
+
+/// A keyboard layout describing which keys are next to each other
+///
+/// Layouts are built from rows of keys, top to bottom. Keys next to each other
+/// in a row are adjacent. Each row is assumed to be offset half a key to the
+/// right of the row above it (as on most physical keyboards), so a key is also
+/// adjacent to the key directly above it and the one above and to the right.
+///
+/// Adjacency is case sensitive; lowercase rows will not match uppercase input.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::KeyLayout;
+///
+/// let layout = KeyLayout::qwerty();
+/// assert!(layout.is_adjacent('q', 'w'));
+/// assert!(layout.is_adjacent('a', 'w'));
+/// assert!(!layout.is_adjacent('q', 'p'));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyLayout {
+    neighbors: HashMap<char, HashSet<char>>,
+    adjacent_cost: f64,
+}
+
+impl KeyLayout {
+    /// Create a layout from rows of keys, top row first
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use stringmetrics::KeyLayout;
+    ///
+    /// let dvorak = KeyLayout::from_rows(&["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"]);
+    /// assert!(dvorak.is_adjacent('a', 'o'));
+    /// ```
+    #[inline]
+    pub fn from_rows(rows: &[&str]) -> Self {
+        let rows: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+        let mut neighbors: HashMap<char, HashSet<char>> = HashMap::new();
+        let mut link = |a: char, b: char| {
+            neighbors.entry(a).or_default().insert(b);
+            neighbors.entry(b).or_default().insert(a);
+        };
+
+        for (r, row) in rows.iter().enumerate() {
+            for pair in row.windows(2) {
+                link(pair[0], pair[1]);
+            }
+            let Some(above) = r.checked_sub(1).map(|r_above| &rows[r_above]) else {
+                continue;
+            };
+            for (i, &key) in row.iter().enumerate() {
+                for &above_key in above.iter().skip(i).take(2) {
+                    link(key, above_key);
+                }
+            }
+        }
+
+        Self {
+            neighbors,
+            adjacent_cost: DEFAULT_ADJACENT_COST,
+        }
+    }
+
+    /// A lowercase US QWERTY layout, letters only
+    #[inline]
+    pub fn qwerty() -> Self {
+        Self::from_rows(&["qwertyuiop", "asdfghjkl", "zxcvbnm"])
+    }
+
+    /// Set the cost of substituting adjacent keys, 0.5 by default
+    ///
+    /// Other edits always cost 1.0. Costs outside of 0 to 1 are allowed but
+    /// will not give meaningful distances.
+    #[inline]
+    #[must_use]
+    pub const fn with_adjacent_cost(mut self, cost: f64) -> Self {
+        self.adjacent_cost = cost;
+        self
+    }
+
+    /// Check whether two keys are next to each other
+    #[inline]
+    pub fn is_adjacent(&self, a: char, b: char) -> bool {
+        self.neighbors.get(&a).is_some_and(|n| n.contains(&b))
+    }
+
+    /// Cost of substituting `a` with `b`
+    fn substitution_cost(&self, a: char, b: char) -> f64 {
+        if a == b {
+            0.0
+        } else if self.is_adjacent(a, b) {
+            self.adjacent_cost
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Levenshtein distance where substituting neighboring keys costs less
+///
+/// Insertions, deletions, and substitutions of distant keys cost 1.0, while
+/// substituting a key with one next to it on `layout` costs the layout's
+/// adjacent cost (0.5 by default). This makes likely typos score as closer
+/// than unlikely ones. Comparison is done on `char`s.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::{levenshtein_keyboard, KeyLayout};
+///
+/// let layout = KeyLayout::qwerty();
+/// // 'w' is next to 'q', 'p' is not
+/// assert_eq!(levenshtein_keyboard("wuick", "quick", &layout), 0.5);
+/// assert_eq!(levenshtein_keyboard("puick", "quick", &layout), 1.0);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn levenshtein_keyboard(a: &str, b: &str, layout: &KeyLayout) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    // Plain two-row DP; weights aren't integers so the u32 machinery doesn't
+    // apply here
+    let mut prev: Vec<f64> = (0..=b_chars.len()).map(|j| j as f64).collect();
+    let mut curr = vec![0.0; b_chars.len() + 1];
+
+    for (i, &a_item) in a_chars.iter().enumerate() {
+        curr[0] = (i + 1) as f64;
+        for (j, &b_item) in b_chars.iter().enumerate() {
+            let sub = prev[j] + layout.substitution_cost(a_item, b_item);
+            let ins = curr[j] + 1.0;
+            let del = prev[j + 1] + 1.0;
+            curr[j + 1] = sub.min(ins).min(del);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b_chars.len()]
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_adjacency() {
+        let layout = KeyLayout::qwerty();
+        assert!(layout.is_adjacent('q', 'w'));
+        assert!(layout.is_adjacent('w', 'q'));
+        assert!(layout.is_adjacent('s', 'w'));
+        assert!(layout.is_adjacent('s', 'e'));
+        assert!(layout.is_adjacent('z', 'a'));
+        assert!(layout.is_adjacent('m', 'k'));
+        assert!(!layout.is_adjacent('s', 'q'));
+        assert!(!layout.is_adjacent('p', 'a'));
+        assert!(!layout.is_adjacent('Q', 'W'));
+    }
+
+    #[test]
+    fn test_keyboard_adjacent_lower() {
+        let layout = KeyLayout::qwerty();
+        let adjacent = levenshtein_keyboard("wuick", "quick", &layout);
+        let distant = levenshtein_keyboard("puick", "quick", &layout);
+        assert_eq!(adjacent, 0.5);
+        assert_eq!(distant, 1.0);
+        assert!(adjacent < distant);
+    }
+
+    #[test]
+    fn test_keyboard_matches_levenshtein() {
+        // With no adjacency discount, this is plain levenshtein
+        let layout = KeyLayout::qwerty().with_adjacent_cost(1.0);
+        assert_eq!(levenshtein_keyboard("kitten", "sitting", &layout), 3.0);
+        assert_eq!(levenshtein_keyboard("", "abc", &layout), 3.0);
+        assert_eq!(levenshtein_keyboard("abc", "", &layout), 3.0);
+        assert_eq!(levenshtein_keyboard("", "", &layout), 0.0);
+    }
+
+    #[test]
+    fn test_keyboard_custom_cost() {
+        let layout = KeyLayout::qwerty().with_adjacent_cost(0.25);
+        assert_eq!(levenshtein_keyboard("wuivk", "quick", &layout), 0.5);
+    }
+}
@@ -2,11 +2,20 @@
 //!
 //! This module contains functions for applying various closeness algorithms. It is not reexporeted.
 
+mod batch;
+#[cfg(feature = "simd")]
+mod bitpar;
 mod implementation;
 mod structures;
+pub use batch::BatchLevenshtein;
 pub use implementation::*;
 pub use structures::*;
 
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+#[cfg(feature = "segmentation")]
+use unicode_segmentation::UnicodeSegmentation;
+
 // fn get_similar_count<I,
 
 /// Basic Levenshtein distance computation
@@ -32,18 +41,103 @@ pub use structures::*;
 /// Note that sometimes the levenshtein distance is defined as having a default
 /// weight of 2 for substitutions. That isn't the case for this implementation -
 /// if you need that functionality, please use [`levenshtein_weight`].
+///
+/// With the `simd` feature enabled, inputs where the shorter string is at most
+/// 64 bytes use a bit-parallel algorithm that computes 64 matrix cells at a
+/// time. Results are identical.
 #[inline]
 pub fn levenshtein(a: &str, b: &str) -> u32 {
+    #[cfg(feature = "simd")]
+    if let Some(res) = bitpar::levenshtein_bitpar(a.as_bytes(), b.as_bytes()) {
+        return res;
+    }
     levenshtein_limit_iter(a.bytes(), b.bytes(), u32::MAX)
 }
 
+/// Levenshtein distance computation counted in the given unit
+///
+/// [`levenshtein`] always counts bytes, which is the same as
+/// [`CountUnit::Byte`]. Use [`CountUnit::CodePoint`] to count `char`s instead,
+/// so that each multibyte character is a single edit. With the `segmentation`
+/// feature, `CountUnit::Grapheme` counts user-perceived characters.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::{levenshtein_by, CountUnit};
+///
+/// // "é" is two bytes but one code point
+/// assert_eq!(levenshtein_by("café", "cafe", CountUnit::Byte), 2);
+/// assert_eq!(levenshtein_by("café", "cafe", CountUnit::CodePoint), 1);
+/// ```
+#[inline]
+pub fn levenshtein_by(a: &str, b: &str, unit: CountUnit) -> u32 {
+    match unit {
+        CountUnit::Byte => levenshtein(a, b),
+        CountUnit::CodePoint => levenshtein_limit_iter(a.chars(), b.chars(), u32::MAX),
+        #[cfg(feature = "segmentation")]
+        CountUnit::Grapheme => {
+            levenshtein_limit_iter(a.graphemes(true), b.graphemes(true), u32::MAX)
+        }
+    }
+}
+
+/// Levenshtein distance normalized to a similarity between 0 and 1
+///
+/// This is `1 - levenshtein(a, b) / max_len`, where `max_len` is the length of
+/// the longer string. Identical strings have a similarity of 1.0, and strings
+/// with nothing in common have a similarity of 0.0. Like [`levenshtein`], this
+/// works on bytes.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::levenshtein_similarity;
+///
+/// assert_eq!(levenshtein_similarity("abcd", "abce"), 0.75);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn levenshtein_similarity(a: &str, b: &str) -> f64 {
+    let max_len = a.len().max(b.len());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - f64::from(levenshtein(a, b)) / max_len as f64
+}
+
+/// Levenshtein distance computation that skips a set of characters
+///
+/// Any characters in `ignore` are removed from both strings before the
+/// distance is computed, which is useful when some characters (e.g.
+/// whitespace or punctuation) are noise. Unlike [`levenshtein`], this compares
+/// `char`s rather than bytes.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashSet;
+/// use stringmetrics::levenshtein_ignoring;
+///
+/// let ignore = HashSet::from([' ', '-']);
+/// assert_eq!(levenshtein_ignoring("a b c", "abc", &ignore), 0);
+/// assert_eq!(levenshtein_ignoring("ab-cd", "a bce", &ignore), 1);
+/// ```
+#[inline]
+pub fn levenshtein_ignoring<S: BuildHasher>(a: &str, b: &str, ignore: &HashSet<char, S>) -> u32 {
+    // A single closure so both iterators have the same type
+    let keep = |c: &char| !ignore.contains(c);
+    levenshtein_limit_iter(a.chars().filter(keep), b.chars().filter(keep), u32::MAX)
+}
+
 /// Levenshtein distance computation with a limit
 ///
 /// This will limitate the levshtein distance up to a given maximum value. The
 /// usual reason for wanting to do this is to avoid unnecessary computation when
 /// a match between two strings can quickly be pruned as "different".
 ///
-/// This function also wraps [`levenshtein_limit_iter`].
+/// This function also wraps [`levenshtein_limit_iter`], or uses the same fast
+/// path as [`levenshtein`] if the `simd` feature is enabled.
 ///
 /// # Example
 ///
@@ -56,6 +150,17 @@ pub fn levenshtein(a: &str, b: &str) -> u32 {
 /// ```
 #[inline]
 pub fn levenshtein_limit(a: &str, b: &str, limit: u32) -> u32 {
+    #[cfg(feature = "simd")]
+    {
+        // The bit-parallel path always scans the full text, so keep the cheap
+        // length rejection that the scalar path does first
+        if a.len().abs_diff(b.len()) > limit as usize {
+            return limit;
+        }
+        if let Some(res) = bitpar::levenshtein_bitpar(a.as_bytes(), b.as_bytes()) {
+            return res.min(limit);
+        }
+    }
     levenshtein_limit_iter(a.bytes(), b.bytes(), limit)
 }
 
@@ -74,6 +179,27 @@ pub fn try_levenshtein(a: &str, b: &str, limit: u32) -> Option<u32> {
     try_levenshtein_iter(a.bytes(), b.bytes(), limit)
 }
 
+/// The same algorithm as [`levenshtein_limit`] but also return whether the
+/// limit was hit
+///
+/// The returned tuple is `(distance, truncated)`. If `truncated` is `false`,
+/// `distance` is the exact levenshtein distance (which may be equal to the
+/// limit). If it is `true`, the real distance is greater than the limit and
+/// `distance` is the limit.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::levenshtein_limit_truncated;
+///
+/// assert_eq!(levenshtein_limit_truncated("kitten", "sitting", 3), (3, false));
+/// assert_eq!(levenshtein_limit_truncated("kitten", "sitting", 2), (2, true));
+/// ```
+#[inline]
+pub fn levenshtein_limit_truncated(a: &str, b: &str, limit: u32) -> (u32, bool) {
+    try_levenshtein(a, b, limit).map_or((limit, true), |res| (res, false))
+}
+
 /// Levenshtein distance computations with adjustable weights and a limit
 ///
 /// Allows setting costs for inserts, deletes and substitutions. See
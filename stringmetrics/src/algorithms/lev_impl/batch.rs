@@ -0,0 +1,120 @@
+//! Levenshtein distance from one query to many candidates
+
+use std::cmp::min;
+
+/// Computes levenshtein distances from one query to many candidates, reusing
+/// work between candidates that share a prefix
+///
+/// The levenshtein matrix is built one row per byte of the candidate, and each
+/// row depends only on the rows above it. Consecutive candidates that start
+/// with the same bytes therefore share those rows; this struct keeps them
+/// around and only computes the rows after the shared prefix. Passing
+/// candidates in sorted order (e.g. a sorted word list) maximizes the reuse.
+///
+/// Results are identical to [`levenshtein`](crate::levenshtein) for any order
+/// of candidates, and distances are likewise counted in bytes. Memory usage is
+/// proportional to the query length times the longest candidate length.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::BatchLevenshtein;
+///
+/// let mut batch = BatchLevenshtein::new("kitten");
+/// let words = ["sit", "sitting", "sittings", "smitten"];
+/// let distances: Vec<u32> = batch.distances(words).collect();
+/// assert_eq!(distances, vec![4, 3, 4, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchLevenshtein {
+    query: String,
+    /// Flattened matrix rows, each `query.len() + 1` long. Row `i` holds the
+    /// distances from every query prefix to the first `i` bytes of `prev`
+    rows: Vec<u32>,
+    /// The last candidate computed
+    prev: Vec<u8>,
+}
+
+impl BatchLevenshtein {
+    /// Create a new batch for comparing against `query`
+    #[inline]
+    pub fn new(query: &str) -> Self {
+        let query = query.to_owned();
+        let len: u32 = query.len().try_into().expect("> u32::MAX items");
+        Self {
+            query,
+            rows: (0..=len).collect(),
+            prev: Vec::new(),
+        }
+    }
+
+    /// The query that distances are computed from
+    #[inline]
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Compute the levenshtein distance between the query and `candidate`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use stringmetrics::BatchLevenshtein;
+    ///
+    /// let mut batch = BatchLevenshtein::new("book");
+    /// assert_eq!(batch.distance("back"), 2);
+    /// // Reuses the rows for "ba"
+    /// assert_eq!(batch.distance("bake"), 3);
+    /// ```
+    #[inline]
+    pub fn distance(&mut self, candidate: &str) -> u32 {
+        let query = self.query.as_bytes();
+        let width = query.len() + 1;
+        let candidate = candidate.as_bytes();
+
+        let shared = self
+            .prev
+            .iter()
+            .zip(candidate)
+            .take_while(|&(a, b)| a == b)
+            .count();
+        self.rows.truncate((shared + 1) * width);
+        self.prev.truncate(shared);
+
+        for (i, &c_item) in candidate.iter().enumerate().skip(shared) {
+            let above = i * width;
+            let mut left = self.rows[above] + 1;
+            self.rows.push(left);
+
+            for (j, &q_item) in query.iter().enumerate() {
+                let sub_base = self.rows[above + j];
+                let del_base = self.rows[above + j + 1];
+                left = if c_item == q_item {
+                    min(min(left, del_base) + 1, sub_base)
+                } else {
+                    min(min(left, del_base), sub_base) + 1
+                };
+                self.rows.push(left);
+            }
+            self.prev.push(c_item);
+        }
+
+        self.rows[self.rows.len() - 1]
+    }
+
+    /// Compute distances to each of `candidates`, in order
+    ///
+    /// This is the same as calling [`BatchLevenshtein::distance`] on each
+    /// candidate.
+    #[inline]
+    pub fn distances<'a, I, S>(&'a mut self, candidates: I) -> impl Iterator<Item = u32> + 'a
+    where
+        I: IntoIterator<Item = S>,
+        I::IntoIter: 'a,
+        S: AsRef<str>,
+    {
+        candidates
+            .into_iter()
+            .map(move |cand| self.distance(cand.as_ref()))
+    }
+}
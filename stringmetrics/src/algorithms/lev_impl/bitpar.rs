@@ -0,0 +1,69 @@
+//! Bit-parallel Levenshtein distance, enabled with the `simd` feature
+//!
+//! This implements [Myers' bit-vector
+//! algorithm](https://doi.org/10.1145/316542.316550) in the form described by
+//! Hyyrö. Rather than storing the DP matrix values, it stores the vertical
+//! differences between cells (which are always -1, 0, or +1) as bits in a
+//! `u64`. A whole column of the matrix is then updated with a handful of
+//! bitwise and arithmetic operations, i.e. 64 cells are processed in parallel
+//! within a single register.
+//!
+//! The shorter input must fit into a single word, so this is only used when it
+//! is between 1 and 64 bytes long. Callers should fall back to the scalar
+//! implementation otherwise.
+
+/// Maximum length of the shorter input that can be computed here
+pub const MAX_LEN: usize = u64::BITS as usize;
+
+/// Compute levenshtein distance between two byte strings
+///
+/// Returns `None` if the shorter string is empty or longer than [`MAX_LEN`],
+/// in which case the scalar algorithm should be used.
+#[inline]
+pub fn levenshtein_bitpar(a: &[u8], b: &[u8]) -> Option<u32> {
+    // The shorter string is encoded in the bit vectors, the longer is iterated
+    let (pattern, text) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let m = pattern.len();
+    if m == 0 || m > MAX_LEN {
+        return None;
+    }
+
+    // Positions at which each byte occurs in the pattern
+    let mut peq = [0u64; 256];
+    for (i, &byte) in pattern.iter().enumerate() {
+        peq[byte as usize] |= 1 << i;
+    }
+
+    // Positive and negative vertical deltas. The first column is 0, 1, 2...
+    // so all deltas start at +1
+    let mut pv = u64::MAX;
+    let mut mv = 0u64;
+    let last_bit = 1 << (m - 1);
+    let mut score = m as u32;
+
+    for &byte in text {
+        let eq = peq[byte as usize];
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+
+        // Horizontal deltas
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+
+        // Track the value of the bottom cell, which ends as the distance
+        if ph & last_bit != 0 {
+            score += 1;
+        } else if mh & last_bit != 0 {
+            score -= 1;
+        }
+
+        // The top row also increases by one per column, so shift in a +1
+        ph = (ph << 1) | 1;
+        mh <<= 1;
+
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+    }
+
+    Some(score)
+}
@@ -34,11 +34,10 @@ where
 
     // Only check b_len because if a_len is 0, the loop won't happen
     if b_len == 0 {
-        if a_len < limit {
+        if a_len <= limit {
             return Some(a_len);
-        } else {
-            return None;
         }
+        return None;
     }
 
     if b_len - a_len > limit {
@@ -54,6 +53,9 @@ where
         // temp_res is also our insertion cost base
         let mut sub_base = i as u32;
         tmp_res = sub_base + 1;
+        // Smallest value in this row. Values never decrease going down, so if
+        // this exceeds the limit then so will the result
+        let mut row_min = tmp_res;
 
         // Go through and do our calculations. we need to preserve the "up left"
         // (sub_base) and "left" (tmp_res) values, the rest can be overwritten
@@ -80,13 +82,18 @@ where
 
             // Save our insertion cost for the next iteration
             work_vec[j] = tmp_res;
+            row_min = min(row_min, tmp_res);
         }
 
-        if tmp_res > limit {
+        if row_min > limit {
             return None;
         }
     }
 
+    if tmp_res > limit {
+        return None;
+    }
+
     Some(tmp_res)
 }
 
@@ -145,11 +152,10 @@ where
     // Only check b_len because if a_len is 0, the loop won't happen
     if b_len == 0 {
         let tmp = a_len * w_del;
-        if tmp < limit {
+        if tmp <= limit {
             return Some(tmp);
-        } else {
-            return None;
         }
+        return None;
     }
 
     if b_len - a_len > limit {
@@ -167,6 +173,7 @@ where
         // temp_res is also our insertion cost base
         let mut sub_base = i as u32 * w_del;
         tmp_res = sub_base + w_del;
+        let mut row_min = tmp_res;
 
         // Go through and do our calculations. we need to preserve the "up left"
         // (sub_base) and "left" (tmp_res) values, the rest can be overwritten
@@ -197,12 +204,17 @@ where
 
             // Save our insertion cost for the next iteration
             work_vec[j] = tmp_res;
+            row_min = min(row_min, tmp_res);
         }
 
-        if tmp_res > limit {
+        if row_min > limit {
             return None;
         }
     }
 
+    if tmp_res > limit {
+        return None;
+    }
+
     Some(tmp_res)
 }
@@ -23,6 +23,7 @@ impl LevWeights {
 
     // Swap insertion and deletion terms
     #[inline]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn swap(&mut self) {
         mem::swap(&mut self.insertion, &mut self.deletion);
     }
@@ -35,6 +36,23 @@ impl Default for LevWeights {
     }
 }
 
+/// The unit that string distances are counted in
+///
+/// See the [crate documentation](crate) for why this matters.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum CountUnit {
+    /// Count bytes of the UTF-8 encoding. Fastest, but a single multibyte
+    /// character may count as several edits
+    Byte,
+    /// Count Unicode code points (`char`s)
+    CodePoint,
+    /// Count extended grapheme clusters, so that e.g. a letter with combining
+    /// accents is a single edit. Requires the `segmentation` feature.
+    #[cfg(feature = "segmentation")]
+    Grapheme,
+}
+
 #[derive(Debug)]
 pub struct LevState<D: DoubleEndedIterator> {
     pub a_iter: Skip<D>,
@@ -83,6 +101,7 @@ impl<D: DoubleEndedIterator<Item = T> + Clone, T: PartialEq> LevState<D> {
     }
 
     #[inline]
+    #[allow(clippy::missing_const_for_fn)]
     pub fn swap_inner(&mut self) {
         mem::swap(&mut self.a_iter, &mut self.b_iter);
         mem::swap(&mut self.a_diff_len, &mut self.b_diff_len);
@@ -1,4 +1,7 @@
 use super::*;
+#[cfg(feature = "simd")]
+use bitpar::{levenshtein_bitpar, MAX_LEN};
+use std::collections::HashSet;
 
 #[test]
 fn test_levweights_swap() {
@@ -67,6 +70,46 @@ fn test_levenshtein_trick_skips() {
     assert_eq!(levenshtein("abccc", "accc"), 1);
 }
 
+#[test]
+fn test_levenshtein_by() {
+    // Precomposed "é" is 2 bytes, 1 code point
+    assert_eq!(levenshtein_by("café", "cafe", CountUnit::Byte), 2);
+    assert_eq!(levenshtein_by("café", "cafe", CountUnit::CodePoint), 1);
+    // "e" + combining acute accent is 3 bytes, 2 code points
+    assert_eq!(levenshtein_by("cafe\u{301}", "café", CountUnit::Byte), 3);
+    assert_eq!(
+        levenshtein_by("cafe\u{301}", "café", CountUnit::CodePoint),
+        2
+    );
+    assert_eq!(
+        levenshtein_by("cafe\u{301}", "cafe", CountUnit::CodePoint),
+        1
+    );
+    assert_eq!(
+        levenshtein_by("kitten", "sitting", CountUnit::Byte),
+        levenshtein("kitten", "sitting")
+    );
+}
+
+#[test]
+#[cfg(feature = "segmentation")]
+fn test_levenshtein_by_grapheme() {
+    // "e" + combining acute accent is 1 grapheme, 2 code points
+    assert_eq!(levenshtein_by("e\u{301}", "e", CountUnit::CodePoint), 1);
+    assert_eq!(levenshtein_by("e\u{301}", "e", CountUnit::Grapheme), 1);
+    assert_eq!(levenshtein_by("e\u{301}x", "x", CountUnit::CodePoint), 2);
+    assert_eq!(levenshtein_by("e\u{301}x", "x", CountUnit::Grapheme), 1);
+    // Not normalized, so the decomposed form still differs from "é"
+    assert_eq!(
+        levenshtein_by("cafe\u{301}", "café", CountUnit::Grapheme),
+        1
+    );
+    // Flags are two regional indicator code points
+    assert_eq!(levenshtein_by("🇺🇸", "", CountUnit::Byte), 8);
+    assert_eq!(levenshtein_by("🇺🇸", "", CountUnit::CodePoint), 2);
+    assert_eq!(levenshtein_by("🇺🇸", "", CountUnit::Grapheme), 1);
+}
+
 #[test]
 fn test_levenshtein_limit_one_empty() {
     assert_eq!(levenshtein_limit("abcdef", "", 3), 3);
@@ -83,6 +126,35 @@ fn test_levenshtein_limit() {
     assert_eq!(levenshtein_limit("ab", "0000", 3), 3);
 }
 
+#[test]
+fn test_levenshtein_limit_not_reached() {
+    // The last column of an intermediate row can be above the limit even if
+    // the final result is not
+    assert_eq!(levenshtein_limit("acbb", "bcbbcc", 4), 3);
+    assert_eq!(levenshtein_limit("aca", "bc", 2), 2);
+    assert_eq!(levenshtein_limit("abc", "abc", 0), 0);
+}
+
+#[test]
+fn test_levenshtein_limit_truncated() {
+    assert_eq!(levenshtein_limit_truncated("abc", "abd", 3), (1, false));
+    assert_eq!(
+        levenshtein_limit_truncated("kitten", "sitting", 3),
+        (3, false)
+    );
+    assert_eq!(
+        levenshtein_limit_truncated("kitten", "sitting", 2),
+        (2, true)
+    );
+    assert_eq!(
+        levenshtein_limit_truncated("abcdef", "000000", 3),
+        (3, true)
+    );
+    assert_eq!(levenshtein_limit_truncated("abcdef", "", 6), (6, false));
+    assert_eq!(levenshtein_limit_truncated("abcdef", "", 5), (5, true));
+    assert_eq!(levenshtein_limit_truncated("", "", 0), (0, false));
+}
+
 #[test]
 fn test_levenshtein_weight_insertion() {
     let weights = LevWeights::new(10, 1, 1);
@@ -133,4 +205,181 @@ fn test_try_levenshtein() {
     assert_eq!(try_levenshtein("ab", "0000", 3), None);
     assert_eq!(try_levenshtein("abcd", "wxya", 1), None);
     assert_eq!(try_levenshtein("angelica", "tortise", 1), None);
+    assert_eq!(try_levenshtein("acbb", "bcbbcc", 4), Some(3));
+    assert_eq!(try_levenshtein("aca", "bc", 2), Some(2));
+    assert_eq!(try_levenshtein("abc", "abc", 0), Some(0));
+}
+
+#[test]
+fn test_try_levenshtein_weight() {
+    let weights = LevWeights::new(1, 1, 1);
+    assert_eq!(
+        try_levenshtein_weight("acbb", "bcbbcc", 4, &weights),
+        Some(3)
+    );
+    assert_eq!(try_levenshtein_weight("abc", "abc", 0, &weights), Some(0));
+    let weights = LevWeights::new(4, 3, 2);
+    assert_eq!(
+        try_levenshtein_weight("kitten", "sitting", 8, &weights),
+        Some(8)
+    );
+    assert_eq!(
+        try_levenshtein_weight("kitten", "sitting", 7, &weights),
+        None
+    );
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_levenshtein_similarity() {
+    assert_eq!(levenshtein_similarity("abcd", "abcd"), 1.0);
+    assert_eq!(levenshtein_similarity("abcd", "abce"), 0.75);
+    assert_eq!(levenshtein_similarity("abcd", "wxyz"), 0.0);
+    assert_eq!(levenshtein_similarity("ab", "abcd"), 0.5);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_levenshtein_similarity_empty() {
+    assert_eq!(levenshtein_similarity("", ""), 1.0);
+    assert_eq!(levenshtein_similarity("abc", ""), 0.0);
+    assert_eq!(levenshtein_similarity("", "abc"), 0.0);
+}
+
+#[test]
+fn test_levenshtein_ignoring() {
+    let spaces = HashSet::from([' ']);
+    assert_eq!(levenshtein_ignoring("a b c", "abc", &spaces), 0);
+    assert_eq!(levenshtein_ignoring("kit ten", "sitting", &spaces), 3);
+    assert_eq!(levenshtein_ignoring("   ", "", &spaces), 0);
+
+    let none = HashSet::new();
+    assert_eq!(levenshtein_ignoring("a b c", "abc", &none), 2);
+    assert_eq!(levenshtein_ignoring("鱼", "雪", &none), 1);
+}
+
+#[cfg(feature = "simd")]
+fn assert_bitpar_parity(a: &str, b: &str) {
+    assert_eq!(
+        levenshtein_bitpar(a.as_bytes(), b.as_bytes()),
+        try_levenshtein_iter(a.bytes(), b.bytes(), u32::MAX),
+        "{a:?} {b:?}"
+    );
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn test_bitpar_basic() {
+    assert_bitpar_parity("kitten", "sitting");
+    assert_bitpar_parity("sitting", "kitten");
+    assert_bitpar_parity("abcd", "abcd");
+    assert_bitpar_parity("a", "b");
+    assert_bitpar_parity("a", "abcdef");
+    assert_bitpar_parity("notate", "to ate");
+    assert_bitpar_parity("to be a bee", "not to bee");
+    assert_bitpar_parity("an orange cat", "an auburn bat");
+    assert_bitpar_parity("鱼", "雪");
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn test_bitpar_full_word() {
+    // Exercise the last bit of the word
+    let a = "x".repeat(MAX_LEN);
+    let b = format!("{}y", "x".repeat(MAX_LEN - 1));
+    assert_bitpar_parity(&a, &b);
+    assert_bitpar_parity(&a, &"y".repeat(100));
+    assert_bitpar_parity(&a, &b.repeat(3));
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn test_bitpar_exhaustive_short() {
+    // Every pair of strings of length 1 to 5 over a small alphabet
+    let mut strings = Vec::new();
+    let mut prev = vec![String::new()];
+    for _ in 0..5 {
+        prev = prev
+            .iter()
+            .flat_map(|s| ['a', 'b', 'c'].map(|c| format!("{s}{c}")))
+            .collect();
+        strings.extend_from_slice(&prev);
+    }
+
+    for a in &strings {
+        for b in &strings {
+            assert_bitpar_parity(a, b);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn test_bitpar_limit_parity() {
+    let long = "abcdefghij".repeat(20);
+    let pairs = [
+        ("kitten", "sitting"),
+        ("abc", long.as_str()),
+        (long.as_str(), "abc"),
+        ("to be a bee", "not to bee"),
+        ("鱼", "雪"),
+    ];
+    for (a, b) in pairs {
+        for limit in [0, 1, 2, 3, 5, 10, 100, u32::MAX] {
+            assert_eq!(
+                levenshtein_limit(a, b, limit),
+                levenshtein_limit_iter(a.bytes(), b.bytes(), limit),
+                "{a:?} {b:?} {limit}"
+            );
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "simd")]
+fn test_bitpar_unsupported() {
+    assert_eq!(levenshtein_bitpar(b"", b"abc"), None);
+    assert_eq!(levenshtein_bitpar(b"abc", b""), None);
+    let long = [b'a'; MAX_LEN + 1];
+    assert_eq!(levenshtein_bitpar(&long, &long), None);
+}
+
+const BATCH_WORDS: [&str; 14] = [
+    "", "a", "ab", "abc", "abd", "b", "back", "bake", "baker", "book", "books", "kitten",
+    "sitting", "sitting",
+];
+
+#[test]
+fn test_batch_sorted() {
+    for query in ["", "a", "book", "kitten", "bakery"] {
+        let mut batch = BatchLevenshtein::new(query);
+        for word in BATCH_WORDS {
+            assert_eq!(
+                batch.distance(word),
+                levenshtein(query, word),
+                "{query} {word}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_batch_unsorted() {
+    let mut words = BATCH_WORDS.to_vec();
+    words.reverse();
+    words.swap(2, 9);
+    for query in ["", "a", "book", "kitten", "bakery"] {
+        let mut batch = BatchLevenshtein::new(query);
+        let res: Vec<u32> = batch.distances(&words).collect();
+        let expected: Vec<u32> = words.iter().map(|w| levenshtein(query, w)).collect();
+        assert_eq!(res, expected, "{query}");
+    }
+}
+
+#[test]
+fn test_batch_multibyte() {
+    let mut batch = BatchLevenshtein::new("鱼雪");
+    assert_eq!(batch.distance("鱼"), levenshtein("鱼雪", "鱼"));
+    assert_eq!(batch.distance("鱼鱼"), levenshtein("鱼雪", "鱼鱼"));
+    assert_eq!(batch.query(), "鱼雪");
 }
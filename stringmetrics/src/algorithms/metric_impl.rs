@@ -0,0 +1,150 @@
+//! # Metric selection and ranking tools (module not reexported)
+
+use super::{levenshtein_similarity, overlap_coefficient, sorensen_dice_n, tversky};
+
+/// A string similarity metric that can be chosen at runtime
+///
+/// Every metric is expressed as a similarity from 0.0 (completely different)
+/// to 1.0 (identical), so that they can be used interchangeably by functions
+/// such as [`similarity_sort`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Metric {
+    /// Normalized Levenshtein distance, see [`levenshtein_similarity`]
+    Levenshtein,
+    /// Jaccard index over character n-grams of the given size
+    Jaccard(usize),
+    /// Sørensen–Dice coefficient over character n-grams of the given size, see
+    /// [`sorensen_dice_n`]
+    SorensenDice(usize),
+    /// Overlap coefficient over character n-grams of the given size, see
+    /// [`overlap_coefficient`]
+    Overlap(usize),
+    /// Tversky index over character n-grams, see [`tversky`]
+    Tversky { n: usize, alpha: f64, beta: f64 },
+}
+
+impl Metric {
+    /// Calculate the similarity of `a` and `b` using this metric
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use stringmetrics::Metric;
+    ///
+    /// assert_eq!(Metric::Levenshtein.similarity("abcd", "abce"), 0.75);
+    /// assert_eq!(Metric::Jaccard(2).similarity("abc", "abd"), 1.0 / 3.0);
+    /// ```
+    #[inline]
+    pub fn similarity(&self, a: &str, b: &str) -> f64 {
+        match *self {
+            Self::Levenshtein => levenshtein_similarity(a, b),
+            Self::Jaccard(n) => tversky(a, b, n, 1.0, 1.0),
+            Self::SorensenDice(n) => sorensen_dice_n(a, b, n),
+            Self::Overlap(n) => overlap_coefficient(a, b, n),
+            Self::Tversky { n, alpha, beta } => tversky(a, b, n, alpha, beta),
+        }
+    }
+}
+
+/// Sort items by how similar they are to a query string
+///
+/// `key` extracts the string to compare from each item. Items are returned
+/// with the most similar first; items with equal similarity keep their
+/// original order.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::{similarity_sort, Metric};
+///
+/// let names = vec!["apple", "maple", "grape"];
+/// let sorted = similarity_sort("appel", names, |s| s, Metric::Levenshtein);
+/// assert_eq!(sorted, vec!["apple", "maple", "grape"]);
+/// ```
+#[inline]
+pub fn similarity_sort<T, F>(query: &str, items: Vec<T>, key: F, metric: Metric) -> Vec<T>
+where
+    F: Fn(&T) -> &str,
+{
+    let mut scored: Vec<(f64, T)> = items
+        .into_iter()
+        .map(|item| (metric.similarity(query, key(&item)), item))
+        .collect();
+
+    // Stable sort, highest similarity first
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.into_iter().map(|(_, item)| item).collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct SearchResult {
+        name: String,
+        id: u32,
+    }
+
+    fn result(name: &str, id: u32) -> SearchResult {
+        SearchResult {
+            name: name.to_owned(),
+            id,
+        }
+    }
+
+    #[test]
+    fn test_metric_similarity() {
+        assert_eq!(Metric::Levenshtein.similarity("abc", "abc"), 1.0);
+        assert_eq!(Metric::Jaccard(2).similarity("night", "nacht"), 1.0 / 7.0);
+        let dice = Metric::Tversky {
+            n: 2,
+            alpha: 0.5,
+            beta: 0.5,
+        };
+        assert_eq!(dice.similarity("night", "nacht"), 0.25);
+    }
+
+    #[test]
+    fn test_metric_similarity_empty() {
+        let metrics = [
+            Metric::Levenshtein,
+            Metric::Jaccard(2),
+            Metric::SorensenDice(2),
+            Metric::Overlap(2),
+            Metric::Tversky {
+                n: 2,
+                alpha: 0.5,
+                beta: 0.5,
+            },
+        ];
+        for metric in metrics {
+            assert_eq!(metric.similarity("", ""), 1.0, "{metric:?}");
+            assert_eq!(metric.similarity("abc", ""), 0.0, "{metric:?}");
+            assert_eq!(metric.similarity("", "abc"), 0.0, "{metric:?}");
+        }
+    }
+
+    #[test]
+    fn test_similarity_sort_structs() {
+        let items = vec![
+            result("orange", 1),
+            result("banana", 2),
+            result("bandana", 3),
+            result("cabana", 4),
+        ];
+        let sorted = similarity_sort("banana", items, |r| &r.name, Metric::Levenshtein);
+        let ids: Vec<u32> = sorted.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![2, 3, 4, 1]);
+    }
+
+    #[test]
+    fn test_similarity_sort_stable() {
+        let items = vec![result("abd", 1), result("abc", 2), result("abe", 3)];
+        let sorted = similarity_sort("abx", items, |r| &r.name, Metric::Levenshtein);
+        let ids: Vec<u32> = sorted.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+    }
+}
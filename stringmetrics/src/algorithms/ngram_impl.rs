@@ -0,0 +1,399 @@
+//! # Character n-gram similarity tools (module not reexported)
+//!
+//! Functions in this module split strings into sets of overlapping character
+//! n-grams (e.g. `"abcd"` with `n = 2` gives `{"ab", "bc", "cd"}`), then compare
+//! those sets.
+
+use std::collections::HashSet;
+
+/// Collect the set of character n-grams in a string
+///
+/// Strings shorter than `n` have no n-grams and produce an empty set.
+pub fn ngram_set(s: &str, n: usize) -> HashSet<Vec<char>> {
+    assert!(n > 0, "n-gram size must be nonzero");
+    let chars: Vec<char> = s.chars().collect();
+    chars.windows(n).map(<[char]>::to_vec).collect()
+}
+
+/// Calculate the Tversky index over the character n-grams of two strings
+///
+/// The Tversky index is an asymmetric generalization of set similarity
+/// measures, defined as:
+///
+/// ```text
+/// |A ∩ B| / (|A ∩ B| + α|A - B| + β|B - A|)
+/// ```
+///
+/// where `A` and `B` are the sets of `n`-grams of `a` and `b` (see
+/// [`jaccard`](crate::jaccard) for more on n-grams). `alpha` and `beta` weight
+/// the items unique to `a` and `b` respectively, and should be non-negative.
+/// Some special cases are:
+///
+/// - `alpha = beta = 0.5` gives the Sørensen–Dice coefficient
+/// - `alpha = beta = 1` gives the Jaccard index
+///
+/// If neither string has any n-grams (i.e. both are shorter than `n`), the
+/// result is `1.0` if the strings are equal and `0.0` otherwise.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::tversky;
+///
+/// // Bigrams {"ni", "ig", "gh", "ht"} and {"na", "ac", "ch", "ht"}
+/// assert_eq!(tversky("night", "nacht", 2, 0.5, 0.5), 0.25);
+/// assert_eq!(tversky("night", "nacht", 2, 1.0, 1.0), 1.0 / 7.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+#[inline]
+pub fn tversky(a: &str, b: &str, n: usize, alpha: f64, beta: f64) -> f64 {
+    tversky_sets(&ngram_set(a, n), &ngram_set(b, n), a == b, alpha, beta)
+}
+
+/// Tversky index of two n-gram sets. `eq` says whether the source strings are
+/// equal, which is only needed if both sets are empty.
+#[allow(clippy::cast_precision_loss)]
+fn tversky_sets(
+    aa: &HashSet<Vec<char>>,
+    bb: &HashSet<Vec<char>>,
+    eq: bool,
+    alpha: f64,
+    beta: f64,
+) -> f64 {
+    if aa.is_empty() && bb.is_empty() {
+        return if eq { 1.0 } else { 0.0 };
+    }
+
+    let ii = aa.intersection(bb).count() as f64;
+    let a_only = aa.difference(bb).count() as f64;
+    let b_only = bb.difference(aa).count() as f64;
+    let denom = beta.mul_add(b_only, alpha.mul_add(a_only, ii));
+
+    // Only possible with zero weights and no shared items
+    if denom == 0.0 {
+        return 0.0;
+    }
+
+    ii / denom
+}
+
+/// Calculate the Sørensen–Dice coefficient over the bigrams of two strings
+///
+/// This is [`sorensen_dice_n`] with `n = 2`, the most common choice for
+/// comparing words.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::sorensen_dice;
+///
+/// assert_eq!(sorensen_dice("night", "nacht"), 0.25);
+/// ```
+#[inline]
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    sorensen_dice_n(a, b, 2)
+}
+
+/// Calculate the Sørensen–Dice coefficient over the character n-grams of two
+/// strings
+///
+/// The coefficient is `2|A ∩ B| / (|A| + |B|)`, where `A` and `B` are the sets
+/// of `n`-grams of `a` and `b`. Smaller `n` is more forgiving of reordering,
+/// larger `n` rewards longer shared runs of characters. This is equivalent to
+/// [`tversky`] with `alpha = beta = 0.5`, and shares its handling of strings
+/// shorter than `n`.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::sorensen_dice_n;
+///
+/// assert_eq!(sorensen_dice_n("context", "contact", 3), 0.4);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+#[inline]
+pub fn sorensen_dice_n(a: &str, b: &str, n: usize) -> f64 {
+    tversky(a, b, n, 0.5, 0.5)
+}
+
+/// Calculate the overlap coefficient over the character n-grams of two strings
+///
+/// Also known as the Szymkiewicz–Simpson coefficient, this is
+/// `|A ∩ B| / min(|A|, |B|)`, where `A` and `B` are the sets of `n`-grams of
+/// `a` and `b`. If one set is a subset of the other the coefficient is 1.0,
+/// which makes it useful for checking whether a short string is contained in a
+/// much longer one.
+///
+/// If neither string has any n-grams, the result is `1.0` if the strings are
+/// equal and `0.0` otherwise. If only one has no n-grams, the result is `0.0`.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::overlap_coefficient;
+///
+/// assert_eq!(overlap_coefficient("night", "midnight train", 2), 1.0);
+/// assert_eq!(overlap_coefficient("night", "nacht", 2), 0.25);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+#[inline]
+pub fn overlap_coefficient(a: &str, b: &str, n: usize) -> f64 {
+    overlap_sets(&ngram_set(a, n), &ngram_set(b, n), a == b)
+}
+
+/// Overlap coefficient of two n-gram sets, see [`tversky_sets`] for `eq`
+#[allow(clippy::cast_precision_loss)]
+fn overlap_sets(aa: &HashSet<Vec<char>>, bb: &HashSet<Vec<char>>, eq: bool) -> f64 {
+    if aa.is_empty() && bb.is_empty() {
+        return if eq { 1.0 } else { 0.0 };
+    }
+
+    let min_len = aa.len().min(bb.len());
+    if min_len == 0 {
+        return 0.0;
+    }
+
+    aa.intersection(bb).count() as f64 / min_len as f64
+}
+
+/// A precomputed set of character n-grams for a string
+///
+/// Functions like [`sorensen_dice_n`] build the n-gram sets of both inputs on
+/// every call. When one string is compared against many others, build a
+/// profile for it once and compare profiles instead. Results are identical to
+/// the function versions.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::NgramProfile;
+///
+/// let query = NgramProfile::new("night", 2);
+/// let candidates = ["nacht", "nigh", "knight"];
+/// let scores: Vec<f64> = candidates
+///     .iter()
+///     .map(|c| query.dice(&NgramProfile::new(c, 2)))
+///     .collect();
+/// assert_eq!(scores[0], 0.25);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NgramProfile {
+    /// The source string, kept only if it has no n-grams to compare
+    short: Option<String>,
+    n: usize,
+    ngrams: HashSet<Vec<char>>,
+}
+
+impl NgramProfile {
+    /// Build the `n`-gram profile of `s`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    #[inline]
+    pub fn new(s: &str, n: usize) -> Self {
+        let ngrams = ngram_set(s, n);
+        Self {
+            short: ngrams.is_empty().then(|| s.to_owned()),
+            n,
+            ngrams,
+        }
+    }
+
+    /// The n-gram size of this profile
+    #[inline]
+    pub const fn n(&self) -> usize {
+        self.n
+    }
+
+    /// The number of unique n-grams in this profile
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ngrams.len()
+    }
+
+    /// Whether the source string was too short to contain any n-grams
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ngrams.is_empty()
+    }
+
+    /// Sørensen–Dice coefficient between two profiles, the same as
+    /// [`sorensen_dice_n`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profiles have different n-gram sizes.
+    #[inline]
+    pub fn dice(&self, other: &Self) -> f64 {
+        self.tversky(other, 0.5, 0.5)
+    }
+
+    /// Jaccard index between two profiles, the same as [`tversky`] with
+    /// `alpha = beta = 1`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profiles have different n-gram sizes.
+    #[inline]
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        self.tversky(other, 1.0, 1.0)
+    }
+
+    /// Overlap coefficient between two profiles, the same as
+    /// [`overlap_coefficient`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profiles have different n-gram sizes.
+    #[inline]
+    pub fn overlap(&self, other: &Self) -> f64 {
+        assert_eq!(self.n, other.n, "n-gram sizes must match");
+        overlap_sets(&self.ngrams, &other.ngrams, self.short == other.short)
+    }
+
+    /// Tversky index between two profiles, the same as [`tversky`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profiles have different n-gram sizes.
+    #[inline]
+    pub fn tversky(&self, other: &Self, alpha: f64, beta: f64) -> f64 {
+        assert_eq!(self.n, other.n, "n-gram sizes must match");
+        tversky_sets(
+            &self.ngrams,
+            &other.ngrams,
+            self.short == other.short,
+            alpha,
+            beta,
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp, clippy::cast_precision_loss)]
+mod tests {
+    use super::*;
+    use crate::jaccard_set;
+
+    #[test]
+    fn test_ngram_set() {
+        let expected: HashSet<Vec<char>> =
+            HashSet::from([vec!['a', 'b'], vec!['b', 'c'], vec!['c', 'd']]);
+        assert_eq!(ngram_set("abcd", 2), expected);
+        assert!(ngram_set("a", 2).is_empty());
+    }
+
+    #[test]
+    fn test_tversky_dice_reduction() {
+        let pairs = [("night", "nacht"), ("context", "contact"), ("abc", "abd")];
+        for (a, b) in pairs {
+            let aa = ngram_set(a, 2);
+            let bb = ngram_set(b, 2);
+            let ii = aa.intersection(&bb).count() as f64;
+            let dice = 2.0 * ii / (aa.len() + bb.len()) as f64;
+            assert!((tversky(a, b, 2, 0.5, 0.5) - dice).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tversky_jaccard_reduction() {
+        let pairs = [("night", "nacht"), ("context", "contact"), ("abc", "abd")];
+        for (a, b) in pairs {
+            let jac = f64::from(jaccard_set(&ngram_set(a, 2), &ngram_set(b, 2)));
+            assert!((tversky(a, b, 2, 1.0, 1.0) - jac).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_tversky_asymmetric() {
+        // "ab" is a subset of "abc", so ignoring items unique to `b` gives 1.0
+        assert_eq!(tversky("ab", "abc", 2, 1.0, 0.0), 1.0);
+        assert_eq!(tversky("ab", "abc", 2, 0.0, 1.0), 0.5);
+    }
+
+    #[test]
+    fn test_sorensen_dice_n() {
+        // Unigrams {c, o, n, t, e, x} and {c, o, n, t, a}
+        assert!((sorensen_dice_n("context", "contact", 1) - 8.0 / 11.0).abs() < 1e-12);
+        // Trigrams share only "con" and "ont"
+        assert!((sorensen_dice_n("context", "contact", 3) - 0.4).abs() < 1e-12);
+        assert_eq!(sorensen_dice("context", "contact"), 0.5);
+        assert_eq!(
+            sorensen_dice("context", "contact"),
+            sorensen_dice_n("context", "contact", 2)
+        );
+    }
+
+    #[test]
+    fn test_overlap_coefficient() {
+        // All bigrams of "night" appear in the longer string
+        assert_eq!(overlap_coefficient("night", "midnight train", 2), 1.0);
+        assert_eq!(overlap_coefficient("midnight train", "night", 2), 1.0);
+        assert_eq!(overlap_coefficient("abc", "abc", 2), 1.0);
+        // One shared bigram, "ht", out of four
+        assert_eq!(overlap_coefficient("night", "nacht", 2), 0.25);
+        assert_eq!(overlap_coefficient("abc", "xyz", 1), 0.0);
+    }
+
+    #[test]
+    fn test_overlap_coefficient_empty() {
+        assert_eq!(overlap_coefficient("", "", 2), 1.0);
+        assert_eq!(overlap_coefficient("a", "b", 2), 0.0);
+        assert_eq!(overlap_coefficient("", "abc", 2), 0.0);
+        assert_eq!(overlap_coefficient("abc", "", 2), 0.0);
+    }
+
+    #[test]
+    fn test_profile_parity() {
+        let query = "context";
+        let candidates = ["contact", "contest", "text", "", "c", "context"];
+        for n in 1..=3 {
+            let profile = NgramProfile::new(query, n);
+            for cand in candidates {
+                let other = NgramProfile::new(cand, n);
+                assert_eq!(profile.dice(&other), sorensen_dice_n(query, cand, n));
+                assert_eq!(profile.jaccard(&other), tversky(query, cand, n, 1.0, 1.0));
+                assert_eq!(profile.overlap(&other), overlap_coefficient(query, cand, n));
+                assert_eq!(
+                    profile.tversky(&other, 0.2, 0.8),
+                    tversky(query, cand, n, 0.2, 0.8)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_short() {
+        let a = NgramProfile::new("a", 2);
+        assert!(a.is_empty());
+        assert_eq!(a.len(), 0);
+        assert_eq!(a.n(), 2);
+        assert_eq!(a.dice(&NgramProfile::new("a", 2)), 1.0);
+        assert_eq!(a.dice(&NgramProfile::new("b", 2)), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "n-gram sizes must match")]
+    fn test_profile_mismatched_n() {
+        NgramProfile::new("abc", 2).dice(&NgramProfile::new("abc", 3));
+    }
+
+    #[test]
+    fn test_tversky_no_ngrams() {
+        assert_eq!(tversky("", "", 2, 0.5, 0.5), 1.0);
+        assert_eq!(tversky("a", "a", 2, 0.5, 0.5), 1.0);
+        assert_eq!(tversky("a", "b", 2, 0.5, 0.5), 0.0);
+        assert_eq!(tversky("", "abc", 2, 0.5, 0.5), 0.0);
+        assert_eq!(tversky("ab", "cd", 2, 0.0, 0.0), 0.0);
+    }
+}
@@ -0,0 +1,77 @@
+//! # Common substring tools (module not reexported)
+
+/// Find the longest contiguous substring shared by two strings
+///
+/// Matching is done on `char`s, so the result is always valid UTF-8 even for
+/// multibyte text. If the strings share no characters, an empty [`String`] is
+/// returned. If there are multiple substrings of the longest length, the one
+/// appearing first in `a` is returned.
+///
+/// # Example
+///
+/// ```
+/// use stringmetrics::longest_common_substring;
+///
+/// assert_eq!(longest_common_substring("abcdef", "zcdemn"), "cde");
+/// assert_eq!(longest_common_substring("abc", "xyz"), "");
+/// ```
+#[inline]
+pub fn longest_common_substring(a: &str, b: &str) -> String {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+
+    // work_vec[j + 1] holds the length of the common suffix ending at the
+    // current item of `a` and item `j` of `b`. We only need the previous row,
+    // which is read from right to left so it isn't overwritten before use.
+    let mut work_vec = vec![0usize; b_chars.len() + 1];
+    let mut best_len = 0;
+    let mut best_end = 0;
+
+    for (i, a_item) in a_chars.iter().enumerate() {
+        for (j, b_item) in b_chars.iter().enumerate().rev() {
+            if a_item == b_item {
+                work_vec[j + 1] = work_vec[j] + 1;
+                // Strictly greater so that ties keep the first match found
+                if work_vec[j + 1] > best_len {
+                    best_len = work_vec[j + 1];
+                    best_end = i + 1;
+                }
+            } else {
+                work_vec[j + 1] = 0;
+            }
+        }
+    }
+
+    a_chars[best_end - best_len..best_end].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcsubstr_basic() {
+        assert_eq!(longest_common_substring("abcdef", "zcdemn"), "cde");
+        assert_eq!(longest_common_substring("zcdemn", "abcdef"), "cde");
+        assert_eq!(longest_common_substring("abcdef", "abcdef"), "abcdef");
+    }
+
+    #[test]
+    fn test_lcsubstr_none() {
+        assert_eq!(longest_common_substring("abc", "xyz"), "");
+        assert_eq!(longest_common_substring("", "xyz"), "");
+        assert_eq!(longest_common_substring("abc", ""), "");
+        assert_eq!(longest_common_substring("", ""), "");
+    }
+
+    #[test]
+    fn test_lcsubstr_tie() {
+        // Both "ab" and "xy" are common; "ab" comes first in `a`
+        assert_eq!(longest_common_substring("abqxy", "xyzab"), "ab");
+    }
+
+    #[test]
+    fn test_lcsubstr_multibyte() {
+        assert_eq!(longest_common_substring("鱼雪鱼", "雪鱼"), "雪鱼");
+    }
+}
@@ -3,7 +3,7 @@
 use std::cmp::min;
 
 #[cfg(not(feature = "bench"))]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq)]
 pub(crate) struct IterPairInfo {
     pub(crate) a_len: u32,
     pub(crate) b_len: u32,
@@ -12,7 +12,7 @@ pub(crate) struct IterPairInfo {
 }
 
 #[cfg(feature = "bench")]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq)]
 pub struct IterPairInfo {
     pub(crate) a_len: u32,
     pub(crate) b_len: u32,
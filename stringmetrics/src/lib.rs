@@ -168,10 +168,13 @@
 //! assert_eq!(levenshtein_limit_iter("😙".chars(), "🔬".chars(), 100), 1);
 //! ```
 //!
-//! If accurate matching on further extended unicode is required, the [unicode
-//! segmentation
-//! crate](https://docs.rs/unicode-segmentation/latest/unicode_segmentation/)
-//! can be used to split on the iterable `graphemes(true)`.
+//! [`levenshtein_by`] offers the same choice for plain distances via
+//! [`CountUnit`].
+//!
+//! If accurate matching on further extended unicode is required, enable the
+//! `segmentation` feature and use `CountUnit::Grapheme`, which splits on
+//! `graphemes(true)` from the [unicode segmentation
+//! crate](https://docs.rs/unicode-segmentation/latest/unicode_segmentation/).
 //!
 //! ## Jaccard Similarity
 //!
@@ -189,6 +192,19 @@
 //! assert_eq!(jaccard(crew1.iter(), crew2.iter()), 0.5);
 //!
 //! ```
+//!
+//! ## Tversky Index
+//!
+//! The Tversky index generalizes set similarity by weighting the items unique
+//! to each set separately. [`tversky`] applies it to the character n-grams of
+//! two strings; with both weights set to 1 it is the Jaccard index, and with
+//! both set to 0.5 it is the Sørensen–Dice coefficient.
+//!
+//! ```
+//! use stringmetrics::tversky;
+//!
+//! assert_eq!(tversky("night", "nacht", 2, 0.5, 0.5), 0.25);
+//! ```
 
 // Strict clippy
 #![warn(
@@ -210,7 +226,7 @@
     clippy::expect_used,
     clippy::unwrap_used,
     clippy::implicit_return,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::exhaustive_structs,
     clippy::shadow_unrelated,
 )]